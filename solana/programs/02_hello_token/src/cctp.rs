@@ -0,0 +1,126 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        hash::hash,
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+/// Circle's Token Messenger Minter program (same address on mainnet-beta
+/// and devnet).
+pub const TOKEN_MESSENGER_MINTER_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPiPYPc6AsJuwueEnWgSgucamXDZwBd53dQ11YiKX");
+
+/// Circle's Message Transmitter program (same address on mainnet-beta and
+/// devnet).
+pub const MESSAGE_TRANSMITTER_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPmbSD7gn5moRt1douHYo4GXHoCr3wPAvfoPaA2hCq");
+
+/// Anchor's instruction discriminator convention
+/// (`sha256("global:<name>")[..8]`), needed here because neither CCTP
+/// program's IDL is vendored as a typed CPI crate in this workspace.
+fn sighash(name: &str) -> [u8; 8] {
+    let digest = hash(format!("global:{name}").as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&digest.to_bytes()[..8]);
+    discriminator
+}
+
+/// Circle's Token Messenger Minter `deposit_for_burn` instruction: burns
+/// `amount` of the local token and registers it for mint on
+/// `destination_domain`, addressed to `mint_recipient`.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_for_burn<'info>(
+    token_messenger_minter_program: &AccountInfo<'info>,
+    account_metas: Vec<AccountMeta>,
+    account_infos: &[AccountInfo<'info>],
+    amount: u64,
+    destination_domain: u32,
+    mint_recipient: [u8; 32],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = sighash("deposit_for_burn").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&destination_domain.to_le_bytes());
+    data.extend_from_slice(&mint_recipient);
+
+    invoke_signed(
+        &Instruction {
+            program_id: *token_messenger_minter_program.key,
+            accounts: account_metas,
+            data,
+        },
+        account_infos,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Token Messenger Minter's `local_token` PDA (seeds
+/// `[b"local_token", mint]`), which tracks the burn limit for an enabled
+/// CCTP token and pins the mint it is allowed to burn/mint. We only need the
+/// `mint` field, so this only decodes the Anchor discriminator and the
+/// first 32 bytes after it; the remaining fields (custody, burn limit,
+/// messenger, etc.) are left unparsed.
+#[derive(Clone)]
+pub struct LocalToken {
+    pub mint: Pubkey,
+}
+
+impl anchor_lang::AccountDeserialize for LocalToken {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        require!(
+            buf.len() >= 8 && buf[..8] == hash(b"account:LocalToken").to_bytes()[..8],
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        require!(
+            buf.len() >= 40,
+            anchor_lang::error::ErrorCode::AccountDidNotDeserialize
+        );
+        let mut mint_bytes = [0u8; 32];
+        mint_bytes.copy_from_slice(&buf[8..40]);
+        Ok(Self {
+            mint: Pubkey::new_from_array(mint_bytes),
+        })
+    }
+}
+
+impl anchor_lang::AccountSerialize for LocalToken {}
+
+impl anchor_lang::Owner for LocalToken {
+    fn owner() -> Pubkey {
+        TOKEN_MESSENGER_MINTER_PROGRAM_ID
+    }
+}
+
+/// Circle's Message Transmitter `receive_message` instruction: verifies the
+/// attestation over `message` and, for a burn message, authorizes the
+/// paired Token Messenger Minter `handle_receive_message` mint.
+pub fn receive_message<'info>(
+    message_transmitter_program: &AccountInfo<'info>,
+    account_metas: Vec<AccountMeta>,
+    account_infos: &[AccountInfo<'info>],
+    message: Vec<u8>,
+    attestation: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = sighash("receive_message").to_vec();
+    message.serialize(&mut data)?;
+    attestation.serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: *message_transmitter_program.key,
+            accounts: account_metas,
+            data,
+        },
+        account_infos,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -10,7 +10,7 @@ use wormhole_anchor_sdk::{token_bridge, wormhole};
 
 use super::{
     state::{ForeignContract, RedeemerConfig, SenderConfig},
-    HelloTokenError, PostedHelloTokenMessage,
+    HelloTokenError, PostedCctpTransferMessage, PostedHelloTokenMessage,
 };
 
 /// AKA `b"bridged"`.
@@ -186,6 +186,85 @@ pub struct RegisterForeignContract<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// Owner of the program set in the [`SenderConfig`] and [`RedeemerConfig`]
+    /// accounts.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloTokenError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Sender Config account. Its `paused` flag gates
+    /// [`SendWrappedTokensWithPayload`] and [`TransferUsdcWithPayload`]. No
+    /// instruction of this program processes [`SendNativeTokensWithPayload`],
+    /// so the flag is not checked for it. Mutable.
+    pub sender_config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloTokenError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Redeemer Config account. Its `paused` flag gates
+    /// [`RedeemNativeTransferWithPayload`]. Mutable.
+    pub redeemer_config: Account<'info, RedeemerConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferLimit<'info> {
+    /// Owner of the program set in the [`SenderConfig`] and [`RedeemerConfig`]
+    /// accounts.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloTokenError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Sender Config account. `max_transfer_amount` rate-limits outbound
+    /// transfers per call to [`SendWrappedTokensWithPayload`] and
+    /// [`TransferUsdcWithPayload`]. No instruction of this program processes
+    /// [`SendNativeTokensWithPayload`], so the limit is not checked for it.
+    /// Mutable.
+    pub sender_config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloTokenError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Redeemer Config account. `max_transfer_amount` rate-limits inbound
+    /// redemptions in [`RedeemNativeTransferWithPayload`]. Mutable.
+    pub redeemer_config: Account<'info, RedeemerConfig>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRelayerFee<'info> {
+    /// Owner of the program set in the [`RedeemerConfig`] account.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloTokenError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Redeemer Config account. This program requires that the `owner`
+    /// specified in the context equals the pubkey specified in this account.
+    /// Mutable.
+    pub config: Account<'info, RedeemerConfig>,
+}
+
 #[derive(Accounts)]
 #[instruction(
     batch_id: u32,
@@ -355,7 +434,8 @@ pub struct RedeemNativeTransferWithPayload<'info> {
         constraint = payer.key() == recipient.key() || payer_token_account.key() == anchor_spl::associated_token::get_associated_token_address(&payer.key(), &mint.key()) @ HelloTokenError::InvalidPayerAta
     )]
     /// CHECK: Payer's token account. If payer != recipient, must be an
-    /// associated token account.
+    /// associated token account. Receives the relayer fee cut out of the
+    /// bridged amount when `config.relayer_fee` is nonzero.
     pub payer_token_account: UncheckedAccount<'info>,
 
     #[account(
@@ -375,7 +455,10 @@ pub struct RedeemNativeTransferWithPayload<'info> {
         bump,
         constraint = foreign_contract.verify(&vaa) @ HelloTokenError::InvalidForeignContract
     )]
-    /// Foreign Contract account. Send tokens to this contract.
+    /// Foreign Contract account. Send tokens to this contract. `verify` also
+    /// checks that the VAA's embedded `from_address` (the original sender on
+    /// the origin chain) matches this registered contract address, so a
+    /// spoofed sender behind the same emitter is rejected.
     pub foreign_contract: Account<'info, ForeignContract>,
 
     #[account(
@@ -484,7 +567,143 @@ pub struct RedeemNativeTransferWithPayload<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(
+    batch_id: u32,
+    amount: u64,
+    recipient_address: [u8; 32],
+    recipient_chain: u16,
+)]
 pub struct SendWrappedTokensWithPayload<'info> {
+    /// Payer will pay Wormhole fee to transfer tokens and create temporary
+    /// token account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Sender Config account. Acts as the Token Bridge sender PDA. Mutable.
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &recipient_chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// Foreign Contract account. Send tokens to this contract.
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &token_bridge_wrapped_meta.chain.to_be_bytes(),
+            &token_bridge_wrapped_meta.token_address,
+        ],
+        bump,
+        seeds::program = token_bridge_program
+    )]
+    /// Token Bridge wrapped mint info. This is the SPL token that will be
+    /// bridged back to its home chain. Mutable.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program
+    )]
+    /// Token Bridge program's wrapped metadata, which stores the token chain
+    /// ID and address of the original asset. Read-only.
+    pub token_bridge_wrapped_meta: Account<'info, token_bridge::WrappedMeta>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+    )]
+    pub from_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"tmp",
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+    )]
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Wormhole program.
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
+
+    /// Token Bridge program.
+    pub token_bridge_program: Program<'info, token_bridge::program::TokenBridge>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.config @ HelloTokenError::InvalidTokenBridgeConfig
+    )]
+    /// Token Bridge config. Mutable.
+    pub token_bridge_config: Account<'info, token_bridge::Config>,
+
+    #[account(
+        address = config.token_bridge.authority_signer @ HelloTokenError::InvalidTokenBridgeAuthoritySigner
+    )]
+    /// CHECK: Token Bridge authority signer. This is the delegate approved to
+    /// burn the wrapped tokens out of `tmp_token_account` on our behalf.
+    /// Read-only.
+    pub token_bridge_authority_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.wormhole_bridge @ HelloTokenError::InvalidWormholeBridge,
+    )]
+    /// Wormhole bridge data. Mutable.
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX_BRIDGED,
+            &token_bridge_sequence.next_value().to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Wormhole Message. Token Bridge program writes info about the
+    /// tokens transferred in this account.
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.emitter @ HelloTokenError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter. Read-only.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.sequence @ HelloTokenError::InvalidTokenBridgeSequence
+    )]
+    /// Token Bridge sequence. Mutable.
+    pub token_bridge_sequence: Account<'info, wormhole::SequenceTracker>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.wormhole_fee_collector @ HelloTokenError::InvalidWormholeFeeCollector
+    )]
+    /// Wormhole fee collector. Mutable.
+    pub wormhole_fee_collector: Account<'info, wormhole::FeeCollector>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -506,3 +725,499 @@ pub struct SendWrappedTokensWithPayload<'info> {
     /// CHECK: Rent sysvar (see [`rent::id()`]). Read-only.
     pub rent: UncheckedAccount<'info>,
 }
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct RedeemWrappedTransferWithPayload<'info> {
+    /// Payer will pay Wormhole fee to transfer tokens and create temporary
+    /// token account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = payer.key() == recipient.key() || payer_token_account.key() == anchor_spl::associated_token::get_associated_token_address(&payer.key(), &mint.key()) @ HelloTokenError::InvalidPayerAta
+    )]
+    /// CHECK: Payer's token account. If payer != recipient, must be an
+    /// associated token account. Receives the relayer fee cut out of the
+    /// bridged amount when `config.relayer_fee` is nonzero, same as in
+    /// [`RedeemNativeTransferWithPayload`].
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Redeemer Config account. Acts as the Token Bridge redeemer PDA.
+    /// Mutable.
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_contract.verify(&vaa) @ HelloTokenError::InvalidForeignContract
+    )]
+    /// Foreign Contract account. Send tokens to this contract. `verify` also
+    /// checks that the VAA's embedded `from_address` (the original sender on
+    /// the origin chain) matches this registered contract address, so a
+    /// spoofed sender behind the same emitter is rejected.
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &token_bridge_wrapped_meta.chain.to_be_bytes(),
+            &token_bridge_wrapped_meta.token_address,
+        ],
+        bump,
+        seeds::program = token_bridge_program
+    )]
+    /// Token Bridge wrapped mint info. This is the wrapped SPL token that
+    /// gets minted on redemption. Mutable.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program
+    )]
+    /// Token Bridge program's wrapped metadata, which stores the token chain
+    /// ID and address of the original asset. Read-only.
+    pub token_bridge_wrapped_meta: Account<'info, token_bridge::WrappedMeta>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: recipient may differ from payer if a relayer paid for this
+    /// transaction.
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"tmp",
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = config
+    )]
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Wormhole program.
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
+
+    /// Token Bridge program.
+    pub token_bridge_program: Program<'info, token_bridge::program::TokenBridge>,
+
+    #[account(
+        address = config.token_bridge.config @ HelloTokenError::InvalidTokenBridgeConfig
+    )]
+    /// Token Bridge config. Read-only.
+    pub token_bridge_config: Account<'info, token_bridge::Config>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program,
+        constraint = vaa.data().to() == *program_id || vaa.data().to() == config.key() @ HelloTokenError::InvalidTransferToAddress,
+        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ HelloTokenError::InvalidTransferToChain,
+    )]
+    /// Verified Wormhole message account. The Wormhole program verified
+    /// signatures and posted the account data here. Read-only.
+    pub vaa: Box<Account<'info, PostedHelloTokenMessage>>,
+
+    #[account(mut)]
+    /// CHECK: Token Bridge claim account. It stores a boolean, whose value
+    /// is true if the bridged assets have been claimed. If the transfer has
+    /// not been redeemed, this account will not exist yet.
+    pub token_bridge_claim: UncheckedAccount<'info>,
+
+    #[account(
+        address = foreign_contract.token_bridge_foreign_endpoint @ HelloTokenError::InvalidTokenBridgeForeignEndpoint
+    )]
+    /// CHECK: Token Bridge foreign endpoint. This account should really be
+    /// one endpoint per chain, but the PDA allows for multiple endpoints for
+    /// each chain! We store the proper endpoint for the emitter chain.
+    pub token_bridge_foreign_endpoint: Account<'info, token_bridge::EndpointDerivation>,
+
+    #[account(
+        seeds = [token_bridge::SEED_PREFIX_MINT_AUTHORITY],
+        bump,
+        seeds::program = token_bridge_program,
+    )]
+    /// CHECK: Token Bridge mint authority. This is the real on-chain mint
+    /// authority of every Token Bridge wrapped mint, so Token Bridge itself
+    /// signs for this PDA inside the `complete_transfer_wrapped_with_payload`
+    /// CPI -- this program never needs to (and cannot) sign on its behalf.
+    pub token_bridge_mint_authority: UncheckedAccount<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        address = rent::id() @ HelloTokenError::InvalidSysvar
+    )]
+    /// CHECK: Rent sysvar (see [`rent::id()`]). Read-only.
+    pub rent: UncheckedAccount<'info>,
+}
+
+/// AKA `b"emitter"`, this program's own Wormhole emitter (as opposed to the
+/// Token Bridge emitter used by the native/wrapped transfer paths), since
+/// CCTP transfers post their Wormhole payload directly instead of going
+/// through the Token Bridge.
+pub const SEED_PREFIX_CCTP_EMITTER: &[u8; 7] = b"emitter";
+
+#[derive(Accounts)]
+#[instruction(
+    batch_id: u32,
+    amount: u64,
+    recipient_address: [u8; 32],
+    recipient_chain: u16,
+)]
+pub struct TransferUsdcWithPayload<'info> {
+    /// Payer will pay Wormhole fee to post the message and create the
+    /// temporary token account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Sender Config account. Acts as the CCTP burn authority delegate's
+    /// owning PDA. Mutable.
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &recipient_chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// Foreign Contract account. Send tokens to this contract.
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    /// CHECK: Delegate approved over `tmp_token_account`, allowed to burn
+    /// `amount` out of it. This is the account Circle's Token Messenger
+    /// Minter program requires as the `burn_token_owner` delegate.
+    pub burn_source_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        address = local_token.mint @ HelloTokenError::InvalidCctpMint
+    )]
+    /// Circle-issued native mint (e.g. USDC) being bridged. Mutable.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+    )]
+    /// Payer's token account that `amount` is moved out of, into
+    /// `tmp_token_account`, before the burn.
+    pub burn_source: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"tmp",
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+    )]
+    /// Temporary custody account that holds `amount` in between approving
+    /// the burn authority and Circle burning it via CPI. Closed at the end
+    /// of the instruction.
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        address = crate::cctp::TOKEN_MESSENGER_MINTER_PROGRAM_ID @ HelloTokenError::InvalidCctpTokenMessengerMinterProgram
+    )]
+    /// CHECK: Circle's Token Messenger Minter program. Read-only.
+    pub token_messenger_minter_program: UncheckedAccount<'info>,
+
+    #[account(
+        address = crate::cctp::MESSAGE_TRANSMITTER_PROGRAM_ID @ HelloTokenError::InvalidCctpMessageTransmitterProgram
+    )]
+    /// CHECK: Circle's Message Transmitter program. Read-only.
+    pub message_transmitter_program: UncheckedAccount<'info>,
+
+    /// CHECK: Message Transmitter config PDA. Holds the next available
+    /// nonce, among other things.
+    pub message_transmitter_config: UncheckedAccount<'info>,
+
+    /// CHECK: Token Messenger PDA, the CCTP-wide registry of remote token
+    /// messengers per domain.
+    pub token_messenger: UncheckedAccount<'info>,
+
+    /// CHECK: Remote Token Messenger PDA for the destination CCTP domain
+    /// that corresponds to `recipient_chain`.
+    pub remote_token_messenger: UncheckedAccount<'info>,
+
+    /// CHECK: Token Minter PDA, the Token Messenger Minter program's mint
+    /// authority.
+    pub token_minter: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// Local Token PDA. Tracks the burn limit for `mint` and pins the mint
+    /// this instruction is allowed to burn.
+    pub local_token: Box<Account<'info, crate::cctp::LocalToken>>,
+
+    /// CHECK: Token Messenger Minter's CPI event authority, required by its
+    /// Anchor `emit_cpi!` instrumentation.
+    pub token_messenger_minter_event_authority: UncheckedAccount<'info>,
+
+    /// Wormhole program.
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
+
+    #[account(
+        mut,
+        seeds = [wormhole::BridgeData::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole_program,
+    )]
+    /// Wormhole bridge data. Mutable.
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX_CCTP_EMITTER,
+            &wormhole_sequence.next_value().to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Wormhole Message. This program posts its
+    /// [`crate::message::CctpTransferMessage`] payload (amount, recipient,
+    /// sender `from_address`) directly into this account, since CCTP
+    /// transfers do not go through the Token Bridge.
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_CCTP_EMITTER],
+        bump,
+    )]
+    /// CHECK: This program's own Wormhole emitter. Read-only.
+    pub wormhole_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            wormhole::SequenceTracker::SEED_PREFIX,
+            wormhole_emitter.key().as_ref()
+        ],
+        bump,
+        seeds::program = wormhole_program,
+    )]
+    /// This program's Wormhole emitter sequence account. Mutable.
+    pub wormhole_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        seeds = [wormhole::FeeCollector::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole_program,
+    )]
+    /// Wormhole fee collector. Mutable.
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        address = clock::id() @ HelloTokenError::InvalidSysvar
+    )]
+    /// CHECK: Clock sysvar (see [`clock::id()`]). Read-only.
+    pub clock: UncheckedAccount<'info>,
+
+    #[account(
+        address = rent::id() @ HelloTokenError::InvalidSysvar
+    )]
+    /// CHECK: Rent sysvar (see [`rent::id()`]). Read-only.
+    pub rent: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct RedeemUsdcWithPayload<'info> {
+    /// Payer will pay Wormhole fee to transfer tokens and create temporary
+    /// token account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = payer.key() == recipient.key() || payer_token_account.key() == anchor_spl::associated_token::get_associated_token_address(&payer.key(), &mint.key()) @ HelloTokenError::InvalidPayerAta
+    )]
+    /// CHECK: Payer's token account. If payer != recipient, must be an
+    /// associated token account. Receives the relayer fee cut, same as in
+    /// [`RedeemNativeTransferWithPayload`].
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Redeemer Config account. Mutable.
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_contract.verify_cctp(&vaa) @ HelloTokenError::InvalidForeignContract
+    )]
+    /// Foreign Contract account. Send tokens to this contract.
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    #[account(
+        mut,
+        address = local_token.mint @ HelloTokenError::InvalidCctpMint
+    )]
+    /// Circle-issued native mint (e.g. USDC) being redeemed. Mutable.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: recipient may differ from payer if a relayer paid for this
+    /// transaction.
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"tmp",
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+        constraint = tmp_token_account.amount == 0 @ HelloTokenError::InvalidCctpMint
+    )]
+    /// CHECK (partially): Deterministic PDA that Circle's `receive_message`
+    /// mints straight into. Its address is the Circle `mint_recipient` the
+    /// origin-chain sender must target, so the redeemer can split the fee
+    /// before the tokens ever reach the recipient's own account. Must be
+    /// empty going in; closed back to `payer` at the end of the instruction.
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Wormhole program.
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program,
+    )]
+    /// Verified Wormhole message account carrying this program's own
+    /// [`crate::message::CctpTransferMessage`] payload, posted directly by
+    /// `transfer_usdc_with_payload` rather than wrapped in a Token Bridge
+    /// payload3 envelope, since CCTP transfers never go through the Token
+    /// Bridge. `foreign_contract.verify_cctp` above checks the emitter
+    /// chain/address and embedded `from_address`. Read-only.
+    pub vaa: Box<Account<'info, PostedCctpTransferMessage>>,
+
+    #[account(
+        address = crate::cctp::MESSAGE_TRANSMITTER_PROGRAM_ID @ HelloTokenError::InvalidCctpMessageTransmitterProgram
+    )]
+    /// CHECK: Circle's Message Transmitter program. CPI target for
+    /// `receive_message`, which verifies Circle's attestation and authorizes
+    /// the mint below.
+    pub message_transmitter_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Message Transmitter config PDA. Mutable.
+    pub message_transmitter_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Used Nonces PDA for the remote domain/nonce pair in `vaa`.
+    /// Prevents the same Circle message from being replayed.
+    pub used_nonces: UncheckedAccount<'info>,
+
+    /// CHECK: Remote Token Messenger PDA for the CCTP domain the transfer
+    /// originated from.
+    pub remote_token_messenger: UncheckedAccount<'info>,
+
+    #[account(
+        address = crate::cctp::TOKEN_MESSENGER_MINTER_PROGRAM_ID @ HelloTokenError::InvalidCctpTokenMessengerMinterProgram
+    )]
+    /// CHECK: Circle's Token Messenger Minter program. Read-only.
+    pub token_messenger_minter_program: UncheckedAccount<'info>,
+
+    /// CHECK: Token Messenger PDA.
+    pub token_messenger: UncheckedAccount<'info>,
+
+    /// CHECK: Token Minter PDA, the Token Messenger Minter program's mint
+    /// authority, which signs the mint CPI.
+    pub token_minter: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// Local Token PDA for `mint`.
+    pub local_token: Box<Account<'info, crate::cctp::LocalToken>>,
+
+    /// CHECK: Token Messenger Minter's CPI event authority.
+    pub token_messenger_minter_event_authority: UncheckedAccount<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        address = rent::id() @ HelloTokenError::InvalidSysvar
+    )]
+    /// CHECK: Rent sysvar (see [`rent::id()`]). Read-only.
+    pub rent: UncheckedAccount<'info>,
+}
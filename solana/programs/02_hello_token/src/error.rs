@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum HelloTokenError {
+    #[msg("OwnerOnly")]
+    OwnerOnly,
+
+    #[msg("InvalidWormholeBridge")]
+    InvalidWormholeBridge,
+
+    #[msg("InvalidWormholeFeeCollector")]
+    InvalidWormholeFeeCollector,
+
+    #[msg("InvalidSysvar")]
+    InvalidSysvar,
+
+    #[msg("InvalidTokenBridgeConfig")]
+    InvalidTokenBridgeConfig,
+
+    #[msg("InvalidTokenBridgeAuthoritySigner")]
+    InvalidTokenBridgeAuthoritySigner,
+
+    #[msg("InvalidTokenBridgeCustodySigner")]
+    InvalidTokenBridgeCustodySigner,
+
+    #[msg("InvalidTokenBridgeEmitter")]
+    InvalidTokenBridgeEmitter,
+
+    #[msg("InvalidTokenBridgeSequence")]
+    InvalidTokenBridgeSequence,
+
+    #[msg("InvalidTokenBridgeForeignEndpoint")]
+    InvalidTokenBridgeForeignEndpoint,
+
+    #[msg("InvalidForeignContract")]
+    InvalidForeignContract,
+
+    #[msg("InvalidPayerAta")]
+    InvalidPayerAta,
+
+    #[msg("InvalidTransferToAddress")]
+    InvalidTransferToAddress,
+
+    #[msg("InvalidTransferToChain")]
+    InvalidTransferToChain,
+
+    #[msg("InvalidTransferTokenChain")]
+    InvalidTransferTokenChain,
+
+    #[msg("InvalidRelayerFee")]
+    InvalidRelayerFee,
+
+    #[msg("Paused")]
+    Paused,
+
+    #[msg("ExceedsMaxTransferAmount")]
+    ExceedsMaxTransferAmount,
+
+    #[msg("InvalidCctpMint")]
+    InvalidCctpMint,
+
+    #[msg("InvalidCctpMessageTransmitterProgram")]
+    InvalidCctpMessageTransmitterProgram,
+
+    #[msg("InvalidCctpTokenMessengerMinterProgram")]
+    InvalidCctpTokenMessengerMinterProgram,
+}
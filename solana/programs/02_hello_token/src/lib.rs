@@ -0,0 +1,556 @@
+use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta};
+use anchor_spl::token::{self, Approve, CloseAccount, Revoke, Transfer};
+use wormhole_anchor_sdk::{token_bridge, wormhole};
+
+pub mod cctp;
+pub mod context;
+pub mod error;
+pub mod message;
+pub mod state;
+
+pub use context::*;
+pub use error::HelloTokenError;
+pub use message::{CctpTransferMessage, HelloTokenMessage, PostedCctpTransferMessage, PostedHelloTokenMessage};
+
+declare_id!("HeLLo1TokenVvVvVvVvVvVvVvVvVvVvVvVvVvVvVvVv");
+
+#[program]
+pub mod hello_token {
+    use super::*;
+
+    /// Lets the owner pause or unpause outbound transfers and inbound
+    /// redemptions without needing to upgrade the program.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.sender_config.paused = paused;
+        ctx.accounts.redeemer_config.paused = paused;
+        Ok(())
+    }
+
+    /// Lets the owner cap (or uncap) the amount allowed per outbound
+    /// transfer and inbound redemption.
+    pub fn set_transfer_limit(
+        ctx: Context<SetTransferLimit>,
+        max_transfer_amount: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.sender_config.max_transfer_amount = max_transfer_amount;
+        ctx.accounts.redeemer_config.max_transfer_amount = max_transfer_amount;
+        Ok(())
+    }
+
+    /// Lets the owner set the app-level relayer fee charged on redemption,
+    /// since Token Bridge payload3 no longer carries a protocol-level one.
+    pub fn update_relayer_fee(
+        ctx: Context<UpdateRelayerFee>,
+        relayer_fee: u64,
+        relayer_fee_precision: u32,
+    ) -> Result<()> {
+        require!(relayer_fee_precision > 0, HelloTokenError::InvalidRelayerFee);
+        require!(
+            relayer_fee < relayer_fee_precision,
+            HelloTokenError::InvalidRelayerFee
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.relayer_fee = relayer_fee;
+        config.relayer_fee_precision = relayer_fee_precision;
+        Ok(())
+    }
+
+    /// Completes a Token Bridge native transfer, then splits the bridged
+    /// amount between the relayer (`payer`) and the `recipient` using the
+    /// redeemer's configured fee, skipping the split entirely when the
+    /// recipient paid for the redemption themselves.
+    pub fn redeem_native_transfer_with_payload(
+        ctx: Context<RedeemNativeTransferWithPayload>,
+        _vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .config
+            .check_transfer_amount(ctx.accounts.vaa.data().amount())?;
+
+        let config_seeds = &[RedeemerConfig::SEED_PREFIX, &[ctx.accounts.config.bump]];
+
+        token_bridge::complete_transfer_native_with_payload(CpiContext::new_with_signer(
+            ctx.accounts.token_bridge_program.to_account_info(),
+            token_bridge::CompleteTransferNativeWithPayload {
+                payer: ctx.accounts.payer.to_account_info(),
+                config: ctx.accounts.token_bridge_config.to_account_info(),
+                vaa: ctx.accounts.vaa.to_account_info(),
+                claim: ctx.accounts.token_bridge_claim.to_account_info(),
+                foreign_endpoint: ctx.accounts.token_bridge_foreign_endpoint.to_account_info(),
+                to: ctx.accounts.tmp_token_account.to_account_info(),
+                redeemer: ctx.accounts.config.to_account_info(),
+                custody: ctx.accounts.token_bridge_custody.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                custody_signer: ctx.accounts.token_bridge_custody_signer.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+
+        let amount = ctx.accounts.vaa.data().amount();
+        let (relayer_fee, recipient_cut) = if ctx.accounts.payer.key() == ctx.accounts.recipient.key()
+        {
+            (0, amount)
+        } else {
+            ctx.accounts.config.compute_relayer_fee(amount)?
+        };
+
+        if relayer_fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.tmp_token_account.to_account_info(),
+                        to: ctx.accounts.payer_token_account.to_account_info(),
+                        authority: ctx.accounts.config.to_account_info(),
+                    },
+                    &[config_seeds],
+                ),
+                relayer_fee,
+            )?;
+        }
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            recipient_cut,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.tmp_token_account.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))
+    }
+
+    /// Bridges a wrapped (non-native) SPL token back to its home chain via
+    /// the Token Bridge's `transfer_wrapped_with_payload` path, mirroring
+    /// the [`SendNativeTokensWithPayload`] accounts for the reverse direction
+    /// of the round trip.
+    pub fn send_wrapped_tokens_with_payload(
+        ctx: Context<SendWrappedTokensWithPayload>,
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        recipient_chain: u16,
+    ) -> Result<()> {
+        ctx.accounts.config.check_transfer_amount(amount)?;
+
+        let config_seeds = &[SenderConfig::SEED_PREFIX, &[ctx.accounts.config.bump]];
+
+        // Move `amount` into the temporary token account the Token Bridge
+        // will burn out of, then delegate it to the Token Bridge's
+        // authority signer so the upcoming CPI can act on our behalf.
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.from_token_account.to_account_info(),
+                    to: ctx.accounts.tmp_token_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        token::approve(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.tmp_token_account.to_account_info(),
+                    delegate: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            amount,
+        )?;
+
+        let payload = HelloTokenMessage::Hello {
+            recipient: recipient_address,
+            from_address: crate::ID.to_bytes(),
+        }
+        .try_to_vec()?;
+
+        token_bridge::transfer_wrapped_with_payload(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_bridge_program.to_account_info(),
+                token_bridge::TransferWrappedWithPayload {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    config: ctx.accounts.token_bridge_config.to_account_info(),
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    from_owner: ctx.accounts.config.to_account_info(),
+                    wrapped_mint: ctx.accounts.mint.to_account_info(),
+                    wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+                    authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                    wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+                    wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+                    wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+                    wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+                    wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    sender: ctx.accounts.config.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            batch_id,
+            amount,
+            recipient_chain,
+            recipient_address,
+            0,
+            payload,
+        )?;
+
+        // The Token Bridge burned the full delegated amount out of the
+        // temporary account; revoke the now-stale delegation and hand the
+        // (empty, rent-exempt) account back to the payer.
+        token::revoke(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.tmp_token_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.tmp_token_account.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))
+    }
+
+    /// Completes a Token Bridge wrapped transfer, minting the wrapped asset
+    /// straight into `tmp_token_account` and splitting it between the
+    /// relayer (`payer`) and the `recipient`, mirroring
+    /// [`redeem_native_transfer_with_payload`] for the wrapped-asset case.
+    pub fn redeem_wrapped_transfer_with_payload(
+        ctx: Context<RedeemWrappedTransferWithPayload>,
+        _vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .config
+            .check_transfer_amount(ctx.accounts.vaa.data().amount())?;
+
+        let config_seeds = &[RedeemerConfig::SEED_PREFIX, &[ctx.accounts.config.bump]];
+
+        token_bridge::complete_transfer_wrapped_with_payload(CpiContext::new_with_signer(
+            ctx.accounts.token_bridge_program.to_account_info(),
+            token_bridge::CompleteTransferWrappedWithPayload {
+                payer: ctx.accounts.payer.to_account_info(),
+                config: ctx.accounts.token_bridge_config.to_account_info(),
+                vaa: ctx.accounts.vaa.to_account_info(),
+                claim: ctx.accounts.token_bridge_claim.to_account_info(),
+                foreign_endpoint: ctx.accounts.token_bridge_foreign_endpoint.to_account_info(),
+                to: ctx.accounts.tmp_token_account.to_account_info(),
+                redeemer: ctx.accounts.config.to_account_info(),
+                wrapped_mint: ctx.accounts.mint.to_account_info(),
+                wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+                mint_authority: ctx.accounts.token_bridge_mint_authority.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+
+        let amount = ctx.accounts.vaa.data().amount();
+        let (relayer_fee, recipient_cut) = if ctx.accounts.payer.key() == ctx.accounts.recipient.key()
+        {
+            (0, amount)
+        } else {
+            ctx.accounts.config.compute_relayer_fee(amount)?
+        };
+
+        if relayer_fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.tmp_token_account.to_account_info(),
+                        to: ctx.accounts.payer_token_account.to_account_info(),
+                        authority: ctx.accounts.config.to_account_info(),
+                    },
+                    &[config_seeds],
+                ),
+                relayer_fee,
+            )?;
+        }
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            recipient_cut,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.tmp_token_account.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))
+    }
+
+    /// Bridges native USDC to a foreign chain via Circle's CCTP
+    /// burn-and-mint path instead of the Token Bridge, posting a
+    /// [`CctpTransferMessage`] directly through this program's own Wormhole
+    /// emitter, since CCTP transfers don't pass through the Token Bridge and
+    /// so have no payload3 envelope to embed a [`HelloTokenMessage`] in.
+    pub fn transfer_usdc_with_payload(
+        ctx: Context<TransferUsdcWithPayload>,
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        _recipient_chain: u16,
+    ) -> Result<()> {
+        ctx.accounts.config.check_transfer_amount(amount)?;
+
+        let config_seeds = &[SenderConfig::SEED_PREFIX, &[ctx.accounts.config.bump]];
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.burn_source.to_account_info(),
+                    to: ctx.accounts.tmp_token_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        token::approve(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.tmp_token_account.to_account_info(),
+                    delegate: ctx.accounts.burn_source_authority.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            amount,
+        )?;
+
+        cctp::deposit_for_burn(
+            &ctx.accounts.token_messenger_minter_program.to_account_info(),
+            vec![
+                AccountMeta::new_readonly(ctx.accounts.burn_source_authority.key(), true),
+                AccountMeta::new(ctx.accounts.tmp_token_account.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.message_transmitter_program.key(), false),
+                AccountMeta::new(ctx.accounts.message_transmitter_config.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_messenger.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.remote_token_messenger.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_minter.key(), false),
+                AccountMeta::new(ctx.accounts.local_token.key(), false),
+                AccountMeta::new(ctx.accounts.mint.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_messenger_minter_event_authority.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_messenger_minter_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+            ],
+            &[
+                ctx.accounts.burn_source_authority.to_account_info(),
+                ctx.accounts.tmp_token_account.to_account_info(),
+                ctx.accounts.message_transmitter_program.to_account_info(),
+                ctx.accounts.message_transmitter_config.to_account_info(),
+                ctx.accounts.token_messenger.to_account_info(),
+                ctx.accounts.remote_token_messenger.to_account_info(),
+                ctx.accounts.token_minter.to_account_info(),
+                ctx.accounts.local_token.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.token_messenger_minter_event_authority.to_account_info(),
+                ctx.accounts.token_messenger_minter_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            amount,
+            ctx.accounts.foreign_contract.cctp_domain,
+            ctx.accounts.foreign_contract.address,
+            &[],
+        )?;
+
+        token::revoke(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.tmp_token_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.tmp_token_account.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+
+        let payload = CctpTransferMessage {
+            amount,
+            recipient: recipient_address,
+            from_address: crate::ID.to_bytes(),
+        }
+        .try_to_vec()?;
+
+        let wormhole_emitter_seeds = &[
+            SEED_PREFIX_CCTP_EMITTER,
+            &[*ctx.bumps.get("wormhole_emitter").unwrap()],
+        ];
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[wormhole_emitter_seeds],
+            ),
+            batch_id,
+            payload,
+            wormhole::Finality::Finalized,
+        )?;
+
+        Ok(())
+    }
+
+    /// Completes a CCTP burn-and-mint transfer by verifying Circle's
+    /// attestation via the Message Transmitter, then splitting the minted
+    /// USDC between the relayer (`payer`) and the `recipient` exactly as
+    /// [`redeem_native_transfer_with_payload`] does for Token Bridge
+    /// transfers.
+    pub fn redeem_usdc_with_payload(
+        ctx: Context<RedeemUsdcWithPayload>,
+        _vaa_hash: [u8; 32],
+        cctp_message: Vec<u8>,
+        cctp_attestation: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts
+            .config
+            .check_transfer_amount(ctx.accounts.vaa.data().amount())?;
+
+        let config_seeds = &[RedeemerConfig::SEED_PREFIX, &[ctx.accounts.config.bump]];
+
+        cctp::receive_message(
+            &ctx.accounts.message_transmitter_program.to_account_info(),
+            vec![
+                AccountMeta::new(ctx.accounts.payer.key(), true),
+                AccountMeta::new(ctx.accounts.message_transmitter_config.key(), false),
+                AccountMeta::new(ctx.accounts.used_nonces.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_messenger_minter_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_messenger.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.remote_token_messenger.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_minter.key(), false),
+                AccountMeta::new(ctx.accounts.local_token.key(), false),
+                AccountMeta::new(ctx.accounts.mint.key(), false),
+                AccountMeta::new(ctx.accounts.tmp_token_account.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_messenger_minter_event_authority.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            ],
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.message_transmitter_config.to_account_info(),
+                ctx.accounts.used_nonces.to_account_info(),
+                ctx.accounts.token_messenger_minter_program.to_account_info(),
+                ctx.accounts.token_messenger.to_account_info(),
+                ctx.accounts.remote_token_messenger.to_account_info(),
+                ctx.accounts.token_minter.to_account_info(),
+                ctx.accounts.local_token.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.tmp_token_account.to_account_info(),
+                ctx.accounts.token_messenger_minter_event_authority.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            cctp_message,
+            cctp_attestation,
+            &[],
+        )?;
+
+        let amount = ctx.accounts.vaa.data().amount();
+        let (relayer_fee, recipient_cut) = if ctx.accounts.payer.key() == ctx.accounts.recipient.key()
+        {
+            (0, amount)
+        } else {
+            ctx.accounts.config.compute_relayer_fee(amount)?
+        };
+
+        if relayer_fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.tmp_token_account.to_account_info(),
+                        to: ctx.accounts.payer_token_account.to_account_info(),
+                        authority: ctx.accounts.config.to_account_info(),
+                    },
+                    &[config_seeds],
+                ),
+                relayer_fee,
+            )?;
+        }
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            recipient_cut,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.tmp_token_account.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::{token_bridge, wormhole};
+
+/// Application-level message bridged inside a Token Bridge payload3
+/// transfer's free-form `payload` bytes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum HelloTokenMessage {
+    Hello {
+        /// Recipient of the bridged tokens on the destination chain.
+        recipient: [u8; 32],
+        /// Address of the HelloToken program that sent this transfer, on
+        /// the origin chain. Checked against the registered
+        /// [`crate::state::ForeignContract`] address on redemption so a
+        /// spoofed sender behind the same Token Bridge emitter is rejected.
+        from_address: [u8; 32],
+    },
+}
+
+impl HelloTokenMessage {
+    pub fn from_address(&self) -> [u8; 32] {
+        match self {
+            Self::Hello { from_address, .. } => *from_address,
+        }
+    }
+}
+
+/// A Token Bridge payload3 transfer, parsed and posted by the Wormhole
+/// program, carrying our [`HelloTokenMessage`] as its inner payload.
+pub type PostedHelloTokenMessage = wormhole::PostedVaa<token_bridge::TransferWithPayload<HelloTokenMessage>>;
+
+/// Application-level message posted directly by the CCTP transfer path,
+/// since CCTP transfers never go through the Token Bridge and so have no
+/// payload3 envelope to ride inside of. Carries everything
+/// [`HelloTokenMessage::Hello`] does, plus the bridged `amount` that
+/// Token Bridge payload3 would otherwise have supplied.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CctpTransferMessage {
+    /// Amount of the bridged asset (e.g. USDC) burned on the origin chain,
+    /// in its native on-chain units.
+    pub amount: u64,
+    /// Recipient of the minted tokens on the destination chain.
+    pub recipient: [u8; 32],
+    /// Address of the HelloToken program that sent this transfer, on the
+    /// origin chain. Checked against the registered
+    /// [`crate::state::ForeignContract`] address on redemption, same as
+    /// [`HelloTokenMessage::from_address`].
+    pub from_address: [u8; 32],
+}
+
+impl CctpTransferMessage {
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn from_address(&self) -> [u8; 32] {
+        self.from_address
+    }
+}
+
+/// A raw Wormhole message posted directly by the CCTP transfer path (see
+/// [`CctpTransferMessage`]), as opposed to [`PostedHelloTokenMessage`]'s
+/// Token Bridge payload3 envelope.
+pub type PostedCctpTransferMessage = wormhole::PostedVaa<CctpTransferMessage>;
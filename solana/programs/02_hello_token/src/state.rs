@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    message::{PostedCctpTransferMessage, PostedHelloTokenMessage},
+    HelloTokenError,
+};
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone)]
+/// Token Bridge program accounts needed to send a transfer.
+pub struct OutboundTokenBridgeAddresses {
+    pub config: Pubkey,
+    pub authority_signer: Pubkey,
+    pub custody_signer: Pubkey,
+    pub emitter: Pubkey,
+    pub sequence: Pubkey,
+    pub wormhole_bridge: Pubkey,
+    pub wormhole_fee_collector: Pubkey,
+}
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone)]
+/// Token Bridge program accounts needed to redeem a transfer.
+pub struct InboundTokenBridgeAddresses {
+    pub config: Pubkey,
+    pub custody_signer: Pubkey,
+}
+
+#[account]
+#[derive(Default)]
+pub struct SenderConfig {
+    /// Program's owner.
+    pub owner: Pubkey,
+    pub bump: u8,
+    /// Token Bridge program's relevant addresses.
+    pub token_bridge: OutboundTokenBridgeAddresses,
+    /// Emergency brake. While set, every outbound transfer is rejected.
+    pub paused: bool,
+    /// Rate guardrail. While set, every outbound transfer above this amount
+    /// is rejected.
+    pub max_transfer_amount: Option<u64>,
+}
+
+impl SenderConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"sender";
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // owner
+        + 1 // bump
+        + 32 * 7 // token_bridge
+        + 1 // paused
+        + (1 + 8); // max_transfer_amount
+
+    /// Rejects `amount` if this config is paused or the amount exceeds the
+    /// configured transfer limit.
+    pub fn check_transfer_amount(&self, amount: u64) -> Result<()> {
+        require!(!self.paused, HelloTokenError::Paused);
+        if let Some(max_transfer_amount) = self.max_transfer_amount {
+            require!(
+                amount <= max_transfer_amount,
+                HelloTokenError::ExceedsMaxTransferAmount
+            );
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct RedeemerConfig {
+    /// Program's owner.
+    pub owner: Pubkey,
+    pub bump: u8,
+    /// Token Bridge program's relevant addresses.
+    pub token_bridge: InboundTokenBridgeAddresses,
+    /// App-level relayer fee charged on redemption, expressed as
+    /// `relayer_fee / relayer_fee_precision` of the bridged amount. Needed
+    /// because Token Bridge payload3 no longer carries a protocol-level
+    /// relayer fee.
+    pub relayer_fee: u64,
+    pub relayer_fee_precision: u32,
+    /// Emergency brake. While set, every redemption is rejected.
+    pub paused: bool,
+    /// Rate guardrail. While set, every redeemed amount above this is
+    /// rejected.
+    pub max_transfer_amount: Option<u64>,
+}
+
+impl RedeemerConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"redeemer";
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // owner
+        + 1 // bump
+        + 32 * 2 // token_bridge
+        + 8 // relayer_fee
+        + 4 // relayer_fee_precision
+        + 1 // paused
+        + (1 + 8); // max_transfer_amount
+
+    /// Splits `amount` into `(relayer_cut, recipient_cut)` using the
+    /// configured fee and precision. Returns `(0, amount)` when no fee is
+    /// set, so callers can skip the split entirely.
+    pub fn compute_relayer_fee(&self, amount: u64) -> Result<(u64, u64)> {
+        if self.relayer_fee == 0 {
+            return Ok((0, amount));
+        }
+
+        let fee = (amount as u128)
+            .checked_mul(self.relayer_fee as u128)
+            .and_then(|product| product.checked_div(self.relayer_fee_precision as u128))
+            .and_then(|fee| u64::try_from(fee).ok())
+            .ok_or(HelloTokenError::InvalidRelayerFee)?;
+
+        Ok((fee, amount.saturating_sub(fee)))
+    }
+
+    /// Rejects `amount` if this config is paused or the amount exceeds the
+    /// configured transfer limit.
+    pub fn check_transfer_amount(&self, amount: u64) -> Result<()> {
+        require!(!self.paused, HelloTokenError::Paused);
+        if let Some(max_transfer_amount) = self.max_transfer_amount {
+            require!(
+                amount <= max_transfer_amount,
+                HelloTokenError::ExceedsMaxTransferAmount
+            );
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct ForeignContract {
+    /// Wormhole chain ID of the foreign contract.
+    pub chain: u16,
+    /// Emitter address of the foreign contract.
+    pub address: [u8; 32],
+    /// Token Bridge program's foreign endpoint account for `chain`.
+    pub token_bridge_foreign_endpoint: Pubkey,
+    /// Circle CCTP domain ID of this chain, used to address CCTP transfers
+    /// to it. Wormhole chain IDs and CCTP domains are both small integers
+    /// assigned per chain, but are distinct namespaces, so this can't be
+    /// derived from `chain`.
+    pub cctp_domain: u32,
+}
+
+impl ForeignContract {
+    pub const SEED_PREFIX: &'static [u8] = b"foreign_contract";
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 2 // chain
+        + 32 // address
+        + 32 // token_bridge_foreign_endpoint
+        + 4; // cctp_domain
+
+    /// Checks that the Wormhole emitter chain/address *and* the original
+    /// sender address embedded in the payload (`from_address`) match this
+    /// registered foreign contract, so a spoofed sender behind the same
+    /// emitter is rejected.
+    pub fn verify(&self, vaa: &PostedHelloTokenMessage) -> bool {
+        self.chain == vaa.emitter_chain()
+            && self.address == *vaa.emitter_address()
+            && self.address == vaa.data().message().from_address()
+    }
+
+    /// Same as [`verify`](Self::verify), but for CCTP-routed transfers,
+    /// whose payload never passes through the Token Bridge and so is parsed
+    /// as [`PostedCctpTransferMessage`] instead of wrapped in a Token Bridge
+    /// payload3 envelope.
+    pub fn verify_cctp(&self, vaa: &PostedCctpTransferMessage) -> bool {
+        self.chain == vaa.emitter_chain()
+            && self.address == *vaa.emitter_address()
+            && self.address == vaa.data().from_address()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_relayer_fee_splits_by_precision() {
+        let config = RedeemerConfig {
+            relayer_fee: 5,
+            relayer_fee_precision: 1_000,
+            ..Default::default()
+        };
+
+        let (fee, recipient_cut) = config.compute_relayer_fee(1_000_000).unwrap();
+        assert_eq!(fee, 5_000);
+        assert_eq!(recipient_cut, 995_000);
+        assert_eq!(fee + recipient_cut, 1_000_000);
+    }
+
+    #[test]
+    fn compute_relayer_fee_zero_fee_takes_nothing() {
+        let config = RedeemerConfig {
+            relayer_fee: 0,
+            relayer_fee_precision: 1_000,
+            ..Default::default()
+        };
+
+        let (fee, recipient_cut) = config.compute_relayer_fee(42).unwrap();
+        assert_eq!(fee, 0);
+        assert_eq!(recipient_cut, 42);
+    }
+
+    #[test]
+    fn check_transfer_amount_rejects_while_paused() {
+        let config = SenderConfig {
+            paused: true,
+            ..Default::default()
+        };
+
+        assert!(config.check_transfer_amount(1).is_err());
+    }
+
+    #[test]
+    fn check_transfer_amount_rejects_above_max() {
+        let config = SenderConfig {
+            max_transfer_amount: Some(100),
+            ..Default::default()
+        };
+
+        assert!(config.check_transfer_amount(100).is_ok());
+        assert!(config.check_transfer_amount(101).is_err());
+    }
+}